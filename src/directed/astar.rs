@@ -0,0 +1,384 @@
+//! Compute a shortest path using variants of the [A* search
+//! algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use indexmap::map::Entry::{Occupied, Vacant};
+use num_traits::Zero;
+
+use super::reverse_path;
+use crate::FxIndexMap;
+
+struct SmallestCostHolder<C> {
+    estimated_cost: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: PartialEq> PartialEq for SmallestCostHolder<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimated_cost == other.estimated_cost && self.cost == other.cost
+    }
+}
+
+impl<C: PartialEq> Eq for SmallestCostHolder<C> {}
+
+impl<C: Ord> PartialOrd for SmallestCostHolder<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for SmallestCostHolder<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so reverse the comparison to get the smallest
+        // estimated cost out first, breaking ties in favour of the largest actual cost
+        // (which is closer to the goal).
+        match other.estimated_cost.cmp(&self.estimated_cost) {
+            Ordering::Equal => self.cost.cmp(&other.cost),
+            s => s,
+        }
+    }
+}
+
+/// Compute a shortest path between `start` and `goal` using a bidirectional A* search,
+/// expanding two heuristic-guided frontiers from both ends at once.
+///
+/// This mirrors [`bfs_bidirectional`](crate::directed::bfs::bfs_bidirectional), but keeps a
+/// separate open set and g-score per direction so that the [heuristic
+/// function](https://en.wikipedia.org/wiki/Admissible_heuristic) can still guide each side
+/// towards the other, just like plain [`astar`]. At every step, the side with the smaller
+/// open set is expanded by a single node (lazily skipping stale heap entries, as in
+/// [`astar`]). Whenever a node relaxed on one side is already known on the other side, the
+/// cost of the full path going through it is a candidate for the best path found so far.
+///
+/// The search stops as soon as the sum of the two sides' minimum **g-scores still in open**
+/// is at least the best full path cost found so far: any path still to be discovered must
+/// cross from an open forward node to an open backward node, so its cost is at least that
+/// sum, which is what guarantees optimality despite the early meeting points not necessarily
+/// lying on the shortest path. Summing estimated costs (`g + h`) instead would double-count
+/// the heuristic and can stop the search too early, so the bound is tracked from the g-scores
+/// directly rather than read off the top of the `estimated_cost`-ordered open sets.
+///
+/// - `start` is the starting node.
+/// - `goal` is the node to reach.
+/// - `successors` returns a list of successors for a given node, along with the cost of
+///   moving from the node to the successor.
+/// - `predecessors` returns a list of predecessors for a given node (the nodes from which it
+///   can be reached in one step), along with the cost of that step.
+/// - `heuristic` returns an approximation of the cost from a node to a target node. It is
+///   called with `goal` as the target while expanding the forward frontier, and with `start`
+///   as the target while expanding the backward frontier, so it must be a valid estimate in
+///   both directions (as is automatically the case for a symmetric distance such as the
+///   Manhattan or Euclidean distance).
+///
+/// The function returns a tuple of the path and its total cost, in a `Some` if a path was
+/// found, or `None` otherwise.
+pub fn astar_bidirectional<N, C, FN, FP, IN, IP, FH>(
+    start: &N,
+    goal: &N,
+    mut successors: FN,
+    mut predecessors: FP,
+    mut heuristic: FH,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    FP: FnMut(&N) -> IP,
+    IN: IntoIterator<Item = (N, C)>,
+    IP: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N, &N) -> C,
+{
+    if start == goal {
+        return Some((vec![start.clone()], C::zero()));
+    }
+
+    let mut g_forward: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    let mut g_backward: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    g_forward.insert(start.clone(), (usize::max_value(), C::zero()));
+    g_backward.insert(goal.clone(), (usize::max_value(), C::zero()));
+
+    // Whether the node at a given index has already been expanded (and so is no longer part
+    // of the open set for the purpose of the g-score bound below).
+    let mut closed_forward = vec![false];
+    let mut closed_backward = vec![false];
+
+    let mut open_forward = BinaryHeap::new();
+    let mut open_backward = BinaryHeap::new();
+    open_forward.push(SmallestCostHolder {
+        estimated_cost: heuristic(start, goal),
+        cost: C::zero(),
+        index: 0,
+    });
+    open_backward.push(SmallestCostHolder {
+        estimated_cost: heuristic(goal, start),
+        cost: C::zero(),
+        index: 0,
+    });
+
+    // Secondary heaps tracking the minimum g-score still in open on each side, used for the
+    // termination bound. They mirror `open_forward`/`open_backward` exactly (same pushes,
+    // same staleness) but are ordered by g alone instead of by estimated cost.
+    let mut open_forward_g = BinaryHeap::new();
+    let mut open_backward_g = BinaryHeap::new();
+    open_forward_g.push(Reverse((C::zero(), 0)));
+    open_backward_g.push(Reverse((C::zero(), 0)));
+
+    // Best full path found so far, as (total cost, forward meeting index, backward meeting index).
+    let mut best: Option<(C, usize, usize)> = None;
+
+    loop {
+        let (Some(_), Some(_)) = (open_forward.peek(), open_backward.peek()) else {
+            break;
+        };
+        if let Some((best_cost, ..)) = best {
+            let min_g_forward = min_open_g(&mut open_forward_g, &g_forward, &closed_forward);
+            let min_g_backward = min_open_g(&mut open_backward_g, &g_backward, &closed_backward);
+            if let (Some(gf), Some(gb)) = (min_g_forward, min_g_backward) {
+                if gf + gb >= best_cost {
+                    break;
+                }
+            }
+        }
+
+        if open_forward.len() <= open_backward.len() {
+            let SmallestCostHolder { cost, index, .. } = open_forward.pop().unwrap();
+            let g = g_forward.get_index(index).unwrap().1 .1;
+            if cost > g {
+                continue;
+            }
+            closed_forward[index] = true;
+            let node = g_forward.get_index(index).unwrap().0.clone();
+            for (next, move_cost) in successors(&node) {
+                let new_g = g + move_cost;
+                let n_index = match g_forward.entry(next.clone()) {
+                    Occupied(mut e) if e.get().1 <= new_g => continue,
+                    Occupied(mut e) => {
+                        e.insert((index, new_g));
+                        closed_forward[e.index()] = false;
+                        e.index()
+                    }
+                    Vacant(e) => {
+                        let idx = e.index();
+                        e.insert((index, new_g));
+                        closed_forward.push(false);
+                        idx
+                    }
+                };
+                open_forward.push(SmallestCostHolder {
+                    estimated_cost: new_g + heuristic(&next, goal),
+                    cost: new_g,
+                    index: n_index,
+                });
+                open_forward_g.push(Reverse((new_g, n_index)));
+                if let Some(b_index) = g_backward.get_index_of(&next) {
+                    let total = new_g + g_backward.get_index(b_index).unwrap().1 .1;
+                    if best.map_or(true, |(best_cost, ..)| total < best_cost) {
+                        best = Some((total, n_index, b_index));
+                    }
+                }
+            }
+        } else {
+            let SmallestCostHolder { cost, index, .. } = open_backward.pop().unwrap();
+            let g = g_backward.get_index(index).unwrap().1 .1;
+            if cost > g {
+                continue;
+            }
+            closed_backward[index] = true;
+            let node = g_backward.get_index(index).unwrap().0.clone();
+            for (next, move_cost) in predecessors(&node) {
+                let new_g = g + move_cost;
+                let n_index = match g_backward.entry(next.clone()) {
+                    Occupied(mut e) if e.get().1 <= new_g => continue,
+                    Occupied(mut e) => {
+                        e.insert((index, new_g));
+                        closed_backward[e.index()] = false;
+                        e.index()
+                    }
+                    Vacant(e) => {
+                        let idx = e.index();
+                        e.insert((index, new_g));
+                        closed_backward.push(false);
+                        idx
+                    }
+                };
+                open_backward.push(SmallestCostHolder {
+                    estimated_cost: new_g + heuristic(&next, start),
+                    cost: new_g,
+                    index: n_index,
+                });
+                open_backward_g.push(Reverse((new_g, n_index)));
+                if let Some(f_index) = g_forward.get_index_of(&next) {
+                    let total = new_g + g_forward.get_index(f_index).unwrap().1 .1;
+                    if best.map_or(true, |(best_cost, ..)| total < best_cost) {
+                        best = Some((total, f_index, n_index));
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|(cost, fa, fb)| {
+        // `fa` and `fb` are each side's own entry for the very node where the two
+        // frontiers met, so the backward half repeats it as its first element once
+        // reversed; skip that duplicate when splicing the two halves together.
+        let mut path = reverse_path(&g_forward, |&(p, _)| p, fa);
+        let mut goal_half = reverse_path(&g_backward, |&(p, _)| p, fb);
+        goal_half.reverse();
+        path.extend(goal_half.into_iter().skip(1));
+        (path, cost)
+    })
+}
+
+/// Peek at the smallest g-score still open in a `(g, index)` min-heap, discarding entries
+/// that are stale (a cheaper path to that index was since found) or that have already been
+/// expanded (and so are no longer part of the open set).
+fn min_open_g<N, C: Ord + Copy>(
+    open_g: &mut BinaryHeap<Reverse<(C, usize)>>,
+    g_scores: &FxIndexMap<N, (usize, C)>,
+    closed: &[bool],
+) -> Option<C> {
+    while let Some(&Reverse((g, index))) = open_g.peek() {
+        if closed[index] || g_scores.get_index(index).unwrap().1 .1 != g {
+            open_g.pop();
+        } else {
+            return Some(g);
+        }
+    }
+    None
+}
+
+/// Result of a single [`AstarState::step`] call.
+pub enum AstarStep<N, C> {
+    /// A path to the goal was found, together with its total cost.
+    Found(Vec<N>, C),
+    /// The open set was emptied before the goal could be reached: no path exists.
+    Exhausted,
+    /// The iteration budget ran out before the goal was reached or the open set emptied.
+    /// The `AstarState` itself already holds the progress made so far: call
+    /// [`step`](AstarState::step) again on it, typically on a later frame or turn.
+    Paused,
+}
+
+/// Resumable state for a budget-limited, anytime variant of [`astar`].
+///
+/// Where [`astar`] runs a single search to completion, `AstarState` lets a caller drive the
+/// same search a few nodes at a time via [`step`](AstarState::step), which is useful when a
+/// full search could take longer than is acceptable in one go (for instance inside a game
+/// loop that must not stall a frame). Between calls to `step`, the best path towards the
+/// goal found so far is available through [`best_path`](AstarState::best_path), so an agent
+/// can start moving before the full path is known.
+pub struct AstarState<N, C> {
+    open: BinaryHeap<SmallestCostHolder<C>>,
+    parents: FxIndexMap<N, (usize, C)>,
+    best_index: usize,
+    best_heuristic: C,
+}
+
+impl<N, C> AstarState<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+{
+    /// Create a fresh search state starting from `start`.
+    pub fn new(start: N) -> Self {
+        let mut parents = FxIndexMap::default();
+        parents.insert(start, (usize::max_value(), C::zero()));
+        let mut open = BinaryHeap::new();
+        open.push(SmallestCostHolder {
+            estimated_cost: C::zero(),
+            cost: C::zero(),
+            index: 0,
+        });
+        AstarState {
+            open,
+            parents,
+            best_index: 0,
+            best_heuristic: C::zero(),
+        }
+    }
+
+    /// Run up to `max_iterations` expansions of the search, then return.
+    ///
+    /// Progress is kept in `self` between calls, so `step` can simply be called again after
+    /// an [`AstarStep::Paused`] to resume where it left off, typically on a later frame or
+    /// turn:
+    ///
+    /// ```ignore
+    /// let mut state = AstarState::new(start);
+    /// loop {
+    ///     match state.step(&mut successors, &mut heuristic, &mut success, 100) {
+    ///         AstarStep::Found(path, cost) => break,
+    ///         AstarStep::Exhausted => break,
+    ///         AstarStep::Paused => continue,
+    ///     }
+    /// }
+    /// ```
+    pub fn step<FN, IN, FH, FS>(
+        &mut self,
+        mut successors: FN,
+        mut heuristic: FH,
+        mut success: FS,
+        max_iterations: usize,
+    ) -> AstarStep<N, C>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+        FS: FnMut(&N) -> bool,
+    {
+        for _ in 0..max_iterations {
+            let Some(SmallestCostHolder { cost, index, .. }) = self.open.pop() else {
+                return AstarStep::Exhausted;
+            };
+            if cost > self.parents.get_index(index).unwrap().1 .1 {
+                // Stale entry: a cheaper path to this node was already found.
+                continue;
+            }
+            let node = self.parents.get_index(index).unwrap().0.clone();
+            let h = heuristic(&node);
+            if index == 0 || h < self.best_heuristic {
+                self.best_index = index;
+                self.best_heuristic = h;
+            }
+            if success(&node) {
+                let path = reverse_path(&self.parents, |&(p, _)| p, index);
+                return AstarStep::Found(path, cost);
+            }
+            for (next, move_cost) in successors(&node) {
+                let new_cost = cost + move_cost;
+                let n_index = match self.parents.entry(next.clone()) {
+                    Occupied(mut e) if e.get().1 <= new_cost => continue,
+                    Occupied(mut e) => {
+                        e.insert((index, new_cost));
+                        e.index()
+                    }
+                    Vacant(e) => {
+                        let idx = e.index();
+                        e.insert((index, new_cost));
+                        idx
+                    }
+                };
+                self.open.push(SmallestCostHolder {
+                    estimated_cost: new_cost + heuristic(&next),
+                    cost: new_cost,
+                    index: n_index,
+                });
+            }
+        }
+        AstarStep::Paused
+    }
+
+    /// Return the best partial path towards the goal found so far, ending at whichever
+    /// visited node had the lowest heuristic value the last time it was expanded.
+    ///
+    /// This can be called between [`step`](AstarState::step) calls to let an agent start
+    /// moving before the full path is known.
+    pub fn best_path(&self) -> Vec<N> {
+        reverse_path(&self.parents, |&(p, _)| p, self.best_index)
+    }
+}
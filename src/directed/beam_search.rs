@@ -0,0 +1,84 @@
+//! Compute a path using [beam
+//! search](https://en.wikipedia.org/wiki/Beam_search), a memory-bounded approximation of
+//! best-first search.
+
+use indexmap::map::Entry::Vacant;
+use std::hash::Hash;
+
+use super::reverse_path;
+use crate::FxIndexMap;
+
+/// Compute a path from `start` to a node for which `success` returns `true`, expanding the
+/// state space level-by-level like [`bfs`](crate::directed::bfs::bfs) but keeping only the
+/// `beam_width` best candidates of each level, as scored by `heuristic` (lower is better).
+///
+/// Unlike [`astar`](crate::directed::astar::astar), beam search discards every node beyond
+/// the beam width at each level, which bounds both memory use and running time on huge
+/// search spaces at the cost of **not being guaranteed to find an optimal, or even any,
+/// path** even when one exists. Using `beam_width = usize::MAX` keeps every candidate at
+/// every level, which degenerates beam search into an ordinary level-by-level best-first
+/// search.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `heuristic` gives a score to a node, lower being more promising; the `beam_width` lowest
+///   scoring successors of each level are kept.
+/// - `success` checks whether the goal has been reached.
+/// - `beam_width` is the maximum number of nodes kept at each level.
+///
+/// The returned path comprises both the start and end node.
+pub fn beam_search<N, C, FN, IN, FH, FS>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+    beam_width: usize,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    if success(&start) {
+        return Some(vec![start]);
+    }
+
+    let mut parents: FxIndexMap<N, usize> = FxIndexMap::default();
+    parents.insert(start, usize::max_value());
+    let mut frontier = vec![0usize];
+
+    while !frontier.is_empty() {
+        let mut candidates: Vec<(usize, N, C)> = Vec::new();
+        for index in frontier {
+            let node = parents.get_index(index).unwrap().0.clone();
+            for next in successors(&node) {
+                if success(&next) {
+                    let mut path = reverse_path(&parents, |&p| p, index);
+                    path.push(next);
+                    return Some(path);
+                }
+                if parents.contains_key(&next) {
+                    continue;
+                }
+                let score = heuristic(&next);
+                candidates.push((index, next, score));
+            }
+        }
+        candidates.sort_by(|a, b| a.2.cmp(&b.2));
+        candidates.truncate(beam_width);
+
+        let mut next_frontier = Vec::with_capacity(candidates.len());
+        for (parent_index, node, _) in candidates {
+            if let Vacant(e) = parents.entry(node) {
+                let index = e.index();
+                e.insert(parent_index);
+                next_frontier.push(index);
+            }
+        }
+        frontier = next_frontier;
+    }
+    None
+}
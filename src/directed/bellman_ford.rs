@@ -0,0 +1,154 @@
+//! Compute a shortest path using the [Bellman-Ford
+//! algorithm](https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm), which unlike
+//! [`dijkstra`](crate::directed::dijkstra) tolerates negative edge weights at the cost of
+//! being slower on graphs that do not need it.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num_traits::Zero;
+
+/// Compute a shortest path using the Bellman-Ford algorithm, allowing negative edge
+/// weights, and detecting negative cycles reachable from `start`.
+///
+/// Internally, this runs the queue-based SPFA (Shortest Path Faster Algorithm) variant of
+/// Bellman-Ford, accelerated by the Small-Label-First (SLF) and Large-Label-Last (LLL)
+/// heuristics: a node whose distance just improved is pushed to the front of the queue
+/// rather than the back when it is cheaper than the node currently at the front, and the
+/// front of the queue is rotated to the back whenever its distance exceeds the average
+/// distance of all queued nodes. Both heuristics keep the correct, negative-weight-tolerant
+/// result of plain SPFA while visiting far fewer nodes in practice.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost of
+///   moving from the node to the successor. Edge costs may be negative.
+///
+/// A map of every node reachable from `start` (other than `start` itself) to its predecessor
+/// and cost from `start` is
+/// returned in a `Some` if no negative cycle is reachable from `start`. If a negative cycle
+/// is reachable, `None` is returned instead, since no shortest path exists in that case.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bellman_ford;
+/// use std::collections::HashMap;
+///
+/// let successors = |n: &char| -> Vec<(char, isize)> {
+///     match n {
+///         'A' => vec![('B', 1), ('C', 5)],
+///         'B' => vec![('C', -3)],
+///         'C' => vec![],
+///         _ => unreachable!(),
+///     }
+/// };
+/// let result = bellman_ford('A', successors).unwrap();
+/// assert_eq!(result[&'C'], ('B', -2));
+/// ```
+pub fn bellman_ford<N, C, FN, IN>(start: N, mut successors: FN) -> Option<HashMap<N, (N, C)>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut dist: HashMap<N, C> = HashMap::new();
+    let mut parent: HashMap<N, N> = HashMap::new();
+    let mut relax_count: HashMap<N, usize> = HashMap::new();
+    let mut in_queue: HashMap<N, bool> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+
+    dist.insert(start.clone(), C::zero());
+    in_queue.insert(start.clone(), true);
+    queue.push_back(start);
+
+    while let Some(node) = pop_front_with_lll(&mut queue, &dist) {
+        in_queue.insert(node.clone(), false);
+        let node_dist = dist[&node];
+        for (successor, cost) in successors(&node) {
+            let candidate = node_dist + cost;
+            let improved = dist
+                .get(&successor)
+                .map_or(true, |&current| candidate < current);
+            if improved {
+                dist.insert(successor.clone(), candidate);
+                parent.insert(successor.clone(), node.clone());
+
+                // Plain SPFA detects a negative cycle once some node has been relaxed more
+                // than |V| times. We do not know the final |V| up front since `successors`
+                // is explored lazily, so `dist.len()` (the number of distinct nodes
+                // discovered so far) is used as a growing stand-in for it instead. This
+                // bound only ever increases as the search proceeds, so a node that is
+                // relaxed once per newly discovered node (the legitimate worst case on a
+                // DAG, where every node's distance can still be improved as the frontier
+                // widens) will not trip it; it is exceeded only by genuine unbounded
+                // relaxation along a negative cycle.
+                let count = relax_count.entry(successor.clone()).or_insert(0);
+                *count += 1;
+                if *count > dist.len() {
+                    return None;
+                }
+
+                if *in_queue.get(&successor).unwrap_or(&false) {
+                    continue;
+                }
+                in_queue.insert(successor.clone(), true);
+                // SLF: favour nodes that are cheaper than the current front of the queue.
+                match queue.front() {
+                    Some(front) if candidate < dist[front] => queue.push_front(successor),
+                    _ => queue.push_back(successor),
+                }
+            }
+        }
+    }
+
+    Some(
+        parent
+            .into_iter()
+            .map(|(n, p)| {
+                let cost = dist[&n];
+                (n, (p, cost))
+            })
+            .collect(),
+    )
+}
+
+/// Pop the front of the queue, applying the Large-Label-Last heuristic: rotate the front to
+/// the back as long as its distance is worse than the average distance of all queued nodes.
+///
+/// `C` has no `Div` bound (costs are not generally divisible), so the average comparison is
+/// rewritten as a multiplication carried out by repeated addition. That makes every rotation
+/// step `O(queue.len())` instead of `O(1)`, so a single call that rotates through most of the
+/// queue costs up to `O(queue.len()²)`; still cheap enough in
+/// practice next to the graph exploration itself, but worth knowing about on very wide
+/// frontiers.
+fn pop_front_with_lll<N, C>(queue: &mut VecDeque<N>, dist: &HashMap<N, C>) -> Option<N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+{
+    if queue.is_empty() {
+        return None;
+    }
+    let sum = queue.iter().fold(C::zero(), |acc, n| acc + dist[n]);
+    let len = queue.len();
+    let first = queue.front().cloned()?;
+    loop {
+        let front = queue.front().cloned()?;
+        let front_dist = dist[&front];
+        // front_dist > sum / len, rewritten without division as front_dist * len > sum.
+        let scaled = (0..len).fold(C::zero(), |acc, _| acc + front_dist);
+        if scaled > sum && queue.len() > 1 {
+            queue.rotate_left(1);
+            if queue.front() == Some(&first) {
+                // We have gone all the way around; every node is above average because of
+                // rounding, so just take the front as-is.
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+    queue.pop_front()
+}
@@ -0,0 +1,65 @@
+use pathfinding::prelude::{AstarState, AstarStep};
+
+// 0 - 1 - 2 - 3 - 4 - 5 (unweighted chain), goal is 5.
+fn successors(n: &i32) -> Vec<(i32, i32)> {
+    [n - 1, n + 1]
+        .into_iter()
+        .filter(|&m| (0..6).contains(&m))
+        .map(|m| (m, 1))
+        .collect()
+}
+
+fn heuristic(n: &i32) -> i32 {
+    (5 - n).abs()
+}
+
+#[test]
+fn step_resumes_across_calls_and_finds_the_optimal_path() {
+    let mut state = AstarState::new(0);
+    let mut rounds = 0;
+    let (path, cost) = loop {
+        rounds += 1;
+        match state.step(successors, heuristic, |&n| n == 5, 1) {
+            AstarStep::Found(path, cost) => break (path, cost),
+            AstarStep::Exhausted => panic!("search exhausted before finding the goal"),
+            AstarStep::Paused => continue,
+        }
+    };
+    assert_eq!(cost, 5);
+    assert_eq!(path, vec![0, 1, 2, 3, 4, 5]);
+    // Budget of 1 expansion per call on a 6-node chain should take more than one round.
+    assert!(rounds > 1);
+}
+
+#[test]
+fn step_reports_exhausted_when_no_path_exists() {
+    let mut state = AstarState::new(0);
+    let no_goal = |_: &i32| false;
+    loop {
+        match state.step(successors, heuristic, no_goal, 10) {
+            AstarStep::Found(..) => panic!("there is no such goal to find"),
+            AstarStep::Exhausted => break,
+            AstarStep::Paused => continue,
+        }
+    }
+}
+
+#[test]
+fn best_path_improves_towards_the_goal_while_paused() {
+    let mut state = AstarState::new(0);
+    state.step(successors, heuristic, |&n| n == 5, 1);
+    let first_best = state.best_path();
+
+    loop {
+        match state.step(successors, heuristic, |&n| n == 5, 1) {
+            AstarStep::Found(..) => break,
+            AstarStep::Exhausted => panic!("search exhausted before finding the goal"),
+            AstarStep::Paused => continue,
+        }
+    }
+    let final_best = state.best_path();
+
+    // The partial path reported early on is a prefix of the final, optimal one.
+    assert!(final_best.starts_with(&first_best));
+    assert_eq!(final_best, vec![0, 1, 2, 3, 4, 5]);
+}
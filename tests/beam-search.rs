@@ -0,0 +1,54 @@
+use pathfinding::prelude::beam_search;
+
+#[test]
+fn finds_path_on_a_chain() {
+    // 0 - 1 - 2 - 3 - 4 - 5
+    let successors = |n: &i32| -> Vec<i32> {
+        [n - 1, n + 1]
+            .into_iter()
+            .filter(|&m| (0..6).contains(&m))
+            .collect()
+    };
+    let heuristic = |n: &i32| (5 - n).abs();
+    let path =
+        beam_search(0, successors, heuristic, |&n| n == 5, usize::MAX).expect("path found");
+    assert_eq!(path, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn no_path_when_goal_is_unreachable() {
+    let successors = |n: &i32| -> Vec<i32> {
+        if *n < 3 {
+            vec![n + 1]
+        } else {
+            vec![]
+        }
+    };
+    let heuristic = |_: &i32| 0;
+    assert_eq!(beam_search(0, successors, heuristic, |&n| n == 10, 10), None);
+}
+
+#[test]
+fn a_narrow_beam_can_miss_a_path_that_full_search_would_find() {
+    // From 0, both 1 and -1 lead away towards a dead end or the goal at 5, but -1 initially
+    // looks more promising under this (deliberately bad) heuristic, and a beam width of 1
+    // only ever keeps the single best-looking candidate, so it commits to the dead end.
+    let successors = |n: &i32| -> Vec<i32> {
+        if *n == 0 {
+            vec![1, -1]
+        } else if (-6..0).contains(n) {
+            vec![n - 1]
+        } else if (1..5).contains(n) {
+            vec![n + 1]
+        } else {
+            vec![]
+        }
+    };
+    let heuristic = |n: &i32| if *n < 0 { *n } else { 5 - n };
+
+    assert_eq!(beam_search(0, successors, heuristic, |&n| n == 5, 1), None);
+    assert_eq!(
+        beam_search(0, successors, heuristic, |&n| n == 5, usize::MAX),
+        Some(vec![0, 1, 2, 3, 4, 5])
+    );
+}
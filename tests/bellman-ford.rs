@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use pathfinding::prelude::bellman_ford;
+
+#[test]
+fn negative_edge_weight_shortens_the_path() {
+    // A -1-> B -(-3)-> C, but also A -5-> C directly: the path through B, helped by a negative
+    // edge on the last hop, is cheaper than the direct one.
+    let successors = |n: &char| -> Vec<(char, isize)> {
+        match n {
+            'A' => vec![('B', 1), ('C', 5)],
+            'B' => vec![('C', -3)],
+            'C' => vec![],
+            _ => unreachable!(),
+        }
+    };
+    let result = bellman_ford('A', successors).expect("no negative cycle here");
+    assert_eq!(result[&'B'], ('A', 1));
+    assert_eq!(result[&'C'], ('B', -2));
+}
+
+#[test]
+fn detects_a_reachable_negative_cycle() {
+    // A -> B -> C -> B, with the B -> C -> B loop summing to -1.
+    let successors = |n: &char| -> Vec<(char, isize)> {
+        match n {
+            'A' => vec![('B', 1)],
+            'B' => vec![('C', 1)],
+            'C' => vec![('B', -2)],
+            _ => unreachable!(),
+        }
+    };
+    assert_eq!(bellman_ford('A', successors), None);
+}
+
+#[test]
+fn does_not_false_positive_on_a_dag_with_many_relaxations() {
+    // A DAG where the final node N is relaxed once per predecessor 0..N, each time with a
+    // strictly better cost (N - n): a legitimate high relaxation count with no cycle at all,
+    // right up against the |V|-sized bound used to detect negative cycles.
+    const N: isize = 12;
+    let successors = move |n: &isize| -> Vec<(isize, isize)> {
+        if *n >= N {
+            vec![]
+        } else {
+            vec![(n + 1, 1), (N, N - 2 * n)]
+        }
+    };
+    let result = bellman_ford(0, successors).expect("a DAG never has a negative cycle");
+    assert_eq!(result[&N].1, 1);
+}
+
+#[test]
+fn no_path_means_the_node_is_simply_absent() {
+    let successors = |n: &i32| -> Vec<(i32, i32)> {
+        if *n < 3 {
+            vec![(n + 1, 1)]
+        } else {
+            vec![]
+        }
+    };
+    let result: HashMap<i32, (i32, i32)> = bellman_ford(0, successors).unwrap();
+    assert!(!result.contains_key(&10));
+    assert_eq!(result[&3], (2, 3));
+}
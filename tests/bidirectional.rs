@@ -0,0 +1,102 @@
+use pathfinding::prelude::{astar_bidirectional, bfs_bidirectional};
+
+#[test]
+fn bfs_bidirectional_finds_shortest_path() {
+    // 0 - 1 - 2 - 3 - 4 (undirected chain)
+    let edges = |n: &i32| -> Vec<i32> {
+        [n - 1, n + 1]
+            .into_iter()
+            .filter(|&m| (0..5).contains(&m))
+            .collect()
+    };
+    let path = bfs_bidirectional(&0, &4, edges, edges).expect("path should be found");
+    assert_eq!(path, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn bfs_bidirectional_same_start_and_goal() {
+    let edges = |_: &i32| -> Vec<i32> { vec![] };
+    assert_eq!(bfs_bidirectional(&0, &0, edges, edges), Some(vec![0]));
+}
+
+#[test]
+fn bfs_bidirectional_no_path() {
+    let edges = |n: &i32| -> Vec<i32> {
+        if *n < 3 {
+            vec![n + 1]
+        } else {
+            vec![]
+        }
+    };
+    // 0 -> 1 -> 2 -> 3, nothing reaches 10.
+    assert_eq!(bfs_bidirectional(&0, &10, edges, edges), None);
+}
+
+// S --1--> A --1--> G
+// S --3-------------^
+// The direct S -> G edge is a tempting, but suboptimal, shortcut: the true shortest path is
+// S -> A -> G at cost 2, which a naive "sum of estimated costs" termination bound (summing
+// g + h on both sides) would miss by stopping as soon as the cheap direct edge is found.
+fn successors(n: &char) -> Vec<(char, i32)> {
+    match n {
+        'S' => vec![('A', 1), ('G', 3)],
+        'A' => vec![('G', 1)],
+        'G' => vec![],
+        _ => unreachable!(),
+    }
+}
+
+fn predecessors(n: &char) -> Vec<(char, i32)> {
+    match n {
+        'G' => vec![('A', 1), ('S', 3)],
+        'A' => vec![('S', 1)],
+        'S' => vec![],
+        _ => unreachable!(),
+    }
+}
+
+fn heuristic(n: &char, target: &char) -> i32 {
+    let distance = |c: char| match c {
+        'S' => 2,
+        'A' => 1,
+        'G' => 0,
+        _ => unreachable!(),
+    };
+    match target {
+        'G' => distance(*n),
+        'S' => 2 - distance(*n),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn astar_bidirectional_does_not_settle_for_a_suboptimal_shortcut() {
+    let (path, cost) = astar_bidirectional(&'S', &'G', successors, predecessors, heuristic)
+        .expect("path should be found");
+    assert_eq!(cost, 2);
+    assert_eq!(path, vec!['S', 'A', 'G']);
+}
+
+#[test]
+fn astar_bidirectional_matches_bfs_on_an_unweighted_chain() {
+    let edges = |n: &i32| -> Vec<(i32, i32)> {
+        [n - 1, n + 1]
+            .into_iter()
+            .filter(|&m| (0..6).contains(&m))
+            .map(|m| (m, 1))
+            .collect()
+    };
+    let unweighted = |n: &i32| -> Vec<i32> {
+        [n - 1, n + 1]
+            .into_iter()
+            .filter(|&m| (0..6).contains(&m))
+            .collect()
+    };
+    let (path, cost) = astar_bidirectional(&0, &5, edges, edges, |a, b| (b - a).abs())
+        .expect("path should be found");
+    assert_eq!(cost, 5);
+    assert_eq!(
+        path,
+        bfs_bidirectional(&0, &5, unweighted, unweighted).unwrap()
+    );
+}